@@ -1,9 +1,105 @@
 use ctcompute::{
-    duration::types::EnrollmentRate, spending::types::SpendingFcn,
-    trial::compute_trial::compute_trial, trial_characteristics::compute_ss_range::compute_ss_range,
+    duration::types::{EnrollmentRate, PiecewiseHazard},
+    endpoint::types::Endpoint,
+    interim::conditional_power::conditional_power as compute_conditional_power,
+    interim::sample_size_reestimation::reestimate_sample_size as compute_reestimate_sample_size,
+    simulation::simulate_trial::simulate_trial as run_trial_simulation,
+    spending::types::SpendingFcn,
+    trial::compute_trial::compute_trial,
+    trial_characteristics::compute_ss_range::compute_ss_range,
 };
 use extendr_api::prelude::*;
 
+/// Builds the testing endpoint: the logrank test, or an RMST-difference
+/// test at the milestone time `tau` when specified.
+/// @param maybe_rmst_tau (optional) milestone time for the restricted mean
+///   survival time endpoint; when omitted, the trial is sized for the
+///   logrank test
+fn parse_endpoint(maybe_rmst_tau: Option<f64>) -> Endpoint {
+    match maybe_rmst_tau {
+        Some(tau) => Endpoint::Rmst { tau },
+        None => Endpoint::LogRank,
+    }
+}
+
+/// Builds a `PiecewiseHazard` from change-point times and per-interval
+/// rates, throwing a descriptive R error if the specification is invalid.
+fn build_piecewise_hazard(times: Vec<f64>, rates: Vec<f64>, label: &str) -> PiecewiseHazard {
+    match PiecewiseHazard::new(times, rates) {
+        Ok(hazard) => hazard,
+        Err(e) => {
+            rprintln!("");
+            extendr_api::throw_r_error(format!("invalid {} hazard specification: {}", label, e))
+        }
+    }
+}
+
+/// Builds the optional dropout `PiecewiseHazard`, requiring that the times
+/// and rates arguments are either both specified or both omitted.
+fn build_maybe_dropout_hazard(
+    maybe_lambda_dropout_times: Option<Vec<f64>>,
+    maybe_lambda_dropout_rates: Option<Vec<f64>>,
+) -> Option<PiecewiseHazard> {
+    match (maybe_lambda_dropout_times, maybe_lambda_dropout_rates) {
+        (Some(times), Some(rates)) => Some(build_piecewise_hazard(times, rates, "dropout")),
+        (None, None) => None,
+        _ => extendr_api::throw_r_error(String::from(
+            "`maybe_lambda_dropout_times` and `maybe_lambda_dropout_rates` must both be \
+                specified, or both omitted",
+        )),
+    }
+}
+
+/// Parses a spending function name (plus its optional parameters) into a
+/// `SpendingFcn`, shared by `ctcompute`, `ss_range` and `simulate_trial`,
+/// and by their upper, lower and (where present) beta spending arguments.
+/// @param maybe_spending_fcn name of the spending function family ("LDOF",
+///   "power", "hsd", "ld_pocock", or "custom")
+/// @param maybe_custom_alpha_spend cumulative spend vector, required when
+///   `maybe_spending_fcn = "custom"`
+/// @param maybe_spending_param family parameter (rho for "power", gamma for
+///   "hsd"), required for those families
+fn parse_spending_fcn(
+    maybe_spending_fcn: Option<&str>,
+    maybe_custom_alpha_spend: Option<&[f64]>,
+    maybe_spending_param: Option<f64>,
+) -> Option<SpendingFcn> {
+    match maybe_spending_fcn {
+        Some("LDOF") => Some(SpendingFcn::LDOF),
+        Some("power") => match maybe_spending_param {
+            Some(rho) => Some(SpendingFcn::PowerFamily { rho }),
+            None => extendr_api::throw_r_error(String::from(
+                "`maybe_spending_param` (or `maybe_beta_spending_param` for the \
+                    beta spending function) (rho) must be specified when \
+                    the spending function is 'power'",
+            )),
+        },
+        Some("hsd") => match maybe_spending_param {
+            Some(gamma) => Some(SpendingFcn::HwangShihDeCani { gamma }),
+            None => extendr_api::throw_r_error(String::from(
+                "`maybe_spending_param` (or `maybe_beta_spending_param` for the \
+                    beta spending function) (gamma) must be specified when \
+                    the spending function is 'hsd'",
+            )),
+        },
+        Some("ld_pocock") => Some(SpendingFcn::LanDeMetsPocock),
+        Some("custom") => match maybe_custom_alpha_spend {
+            Some(custom_alpha_spend) => Some(SpendingFcn::Custom {
+                cumulative_spend: custom_alpha_spend.into(),
+            }),
+            None => extendr_api::throw_r_error(String::from(
+                "`maybe_custom_alpha_spend` (or `maybe_custom_beta_spend` for the \
+                    beta spending function) must be specified when \
+                    the spending function is 'custom'",
+            )),
+        },
+        None => None,
+        Some(unknown_spend) => {
+            extendr_api::throw_r_error(format!("invalid spending function: `{}`", unknown_spend))
+        }
+    }
+}
+
 /// Computes characteristics of a clinical trial with specified
 /// power, sample size, etc.
 /// @param n_patients the number of patients in the hypothetical trial
@@ -13,16 +109,26 @@ use extendr_api::prelude::*;
 /// @param maybe_upper_spending_fcn (optional) spending function type for upper bound
 /// @param maybe_look_fractions (optional) information fractions at each trial look
 /// @param prop_treated proportion of patients who will be randomized to treatment arm
-/// @param lambda_event_trt hazard rate for event for treatment arm (assuming constant hazard)
-/// @param lambda_event_ctrl hazard rate for event for control arm (assuming constant hazard)
-/// @param maybe_lambda_dropout (optional) hazard rate for dropout (assuming constant hazard)
+/// @param lambda_event_trt_rates piecewise-constant hazard rates for event for treatment arm, one per interval
+/// @param lambda_event_trt_times change-point times at which `lambda_event_trt_rates` take effect (first entry must be 0)
+/// @param lambda_event_ctrl_rates piecewise-constant hazard rates for event for control arm, one per interval
+/// @param lambda_event_ctrl_times change-point times at which `lambda_event_ctrl_rates` take effect (first entry must be 0)
+/// @param maybe_lambda_dropout_rates (optional) piecewise-constant hazard rates for dropout, one per interval
+/// @param maybe_lambda_dropout_times (optional) change-point times at which `maybe_lambda_dropout_rates` take effect; must be specified together with `maybe_lambda_dropout_rates`
 /// @param enrollment_rates rates at which patients will be enrolled into the study
 /// @param enrollment_times times at which enrollment rates apply
 /// @param maybe_custom_alpha_spend when spending functions are specified as "custom", specifies the *cumulative* alpha to be spent at each look
+/// @param maybe_spending_param (optional) family parameter for the alpha spending function: rho for "power" (rho ~= 1 is near-Pocock, rho ~= 3 is near-OBF), or gamma for "hsd"
+/// @param maybe_beta_spending_fcn (optional) spending function type for the futility (type-II error) bound; when omitted, no futility monitoring is performed
+/// @param maybe_custom_beta_spend when `maybe_beta_spending_fcn = "custom"`, specifies the *cumulative* beta to be spent at each look
+/// @param maybe_beta_spending_param (optional) family parameter for the beta spending function: rho for "power", or gamma for "hsd" (independent of `maybe_spending_param`, so the futility bound can use a different shape than the efficacy bound)
+/// @param binding whether the futility bound is binding, i.e. whether the alpha boundaries are recomputed assuming the trial stops for futility when crossed
+/// @param maybe_rmst_tau (optional) milestone time for a restricted mean survival time (RMST) endpoint; when omitted, the trial is sized for the logrank test
 /// @param r controls grid size for integration; recommended to be set to 32, and no less than 16 failing that
 /// @param tol desired precision of calculations. Results are not guaranteed to be within this distance of true values, but smaller tol values lead to more accurate calculations
 /// @export
 #[extendr]
+#[allow(clippy::too_many_arguments)]
 fn ctcompute(
     n_patients: usize,
     alpha: f64,
@@ -31,55 +137,63 @@ fn ctcompute(
     maybe_upper_spending_fcn: Option<String>,
     maybe_look_fractions: Option<Vec<f64>>,
     prop_treated: f64,
-    lambda_event_trt: f64,
-    lambda_event_ctrl: f64,
-    maybe_lambda_dropout: Option<f64>,
+    lambda_event_trt_rates: Vec<f64>,
+    lambda_event_trt_times: Vec<f64>,
+    lambda_event_ctrl_rates: Vec<f64>,
+    lambda_event_ctrl_times: Vec<f64>,
+    maybe_lambda_dropout_rates: Option<Vec<f64>>,
+    maybe_lambda_dropout_times: Option<Vec<f64>>,
     enrollment_rates: Vec<f64>,
     enrollment_times: Vec<f64>,
     maybe_custom_alpha_spend: Option<Vec<f64>>,
+    maybe_spending_param: Option<f64>,
+    maybe_beta_spending_fcn: Option<String>,
+    maybe_custom_beta_spend: Option<Vec<f64>>,
+    maybe_beta_spending_param: Option<f64>,
+    binding: bool,
+    maybe_rmst_tau: Option<f64>,
     r: usize,
     tol: f64,
 ) -> List {
-    let maybe_lower_spending_fcn = match (
+    let maybe_lower_spending_fcn = parse_spending_fcn(
         maybe_lower_spending_fcn.as_deref(),
         maybe_custom_alpha_spend.as_deref(),
-    ) {
-        (Some("LDOF"), _) => Some(SpendingFcn::LDOF),
-        (Some("custom"), Some(custom_alpha_spend)) => Some(SpendingFcn::Custom {
-            cumulative_spend: custom_alpha_spend.into(),
-        }),
-        (Some("custom"), None) => extendr_api::throw_r_error(String::from(
-            "`maybe_custom_alpha_spend` must be specified when \
-                maybe_lower_spending_fcn = 'custom'",
-        )),
-        (None, _) => None,
-        (Some(unknown_spend), _) => {
-            extendr_api::throw_r_error(format!("invalid spending function: `{}`", unknown_spend))
-        }
-    };
+        maybe_spending_param,
+    );
     let maybe_lower_spending_fcn_ref = maybe_lower_spending_fcn.as_ref();
 
-    let maybe_upper_spending_fcn = match (
+    let maybe_upper_spending_fcn = parse_spending_fcn(
         maybe_upper_spending_fcn.as_deref(),
         maybe_custom_alpha_spend.as_deref(),
-    ) {
-        (Some("LDOF"), _) => Some(SpendingFcn::LDOF),
-        (Some("custom"), Some(custom_alpha_spend)) => Some(SpendingFcn::Custom {
-            cumulative_spend: custom_alpha_spend.into(),
-        }),
-        (Some("custom"), None) => extendr_api::throw_r_error(String::from(
-            "`maybe_custom_alpha_spend` must be specified when \
-                maybe_upper_spending_fcn = 'custom'",
-        )),
-        (None, _) => None,
-        (Some(unknown_spend), _) => {
-            extendr_api::throw_r_error(format!("invalid spending function: `{}`", unknown_spend))
-        }
-    };
+        maybe_spending_param,
+    );
     let maybe_upper_spending_fcn_ref = maybe_upper_spending_fcn.as_ref();
 
+    let maybe_beta_spending_fcn = parse_spending_fcn(
+        maybe_beta_spending_fcn.as_deref(),
+        maybe_custom_beta_spend.as_deref(),
+        maybe_beta_spending_param,
+    );
+    let maybe_beta_spending_fcn_ref = maybe_beta_spending_fcn.as_ref();
+
     let maybe_look_fractions_ref = maybe_look_fractions.as_ref();
 
+    let lambda_event_trt = build_piecewise_hazard(
+        lambda_event_trt_times,
+        lambda_event_trt_rates,
+        "treatment arm event",
+    );
+    let lambda_event_ctrl = build_piecewise_hazard(
+        lambda_event_ctrl_times,
+        lambda_event_ctrl_rates,
+        "control arm event",
+    );
+    let maybe_lambda_dropout =
+        build_maybe_dropout_hazard(maybe_lambda_dropout_times, maybe_lambda_dropout_rates);
+    let maybe_lambda_dropout_ref = maybe_lambda_dropout.as_ref();
+
+    let endpoint = parse_endpoint(maybe_rmst_tau);
+
     let enrollment_rate = match EnrollmentRate::new(enrollment_times, enrollment_rates) {
         Ok(er) => er,
         Err(e) => {
@@ -94,12 +208,15 @@ fn ctcompute(
         power,
         maybe_lower_spending_fcn_ref,
         maybe_upper_spending_fcn_ref,
+        maybe_beta_spending_fcn_ref,
+        binding,
         maybe_look_fractions_ref,
         prop_treated,
-        lambda_event_trt,
-        lambda_event_ctrl,
-        maybe_lambda_dropout,
+        &lambda_event_trt,
+        &lambda_event_ctrl,
+        maybe_lambda_dropout_ref,
         &enrollment_rate,
+        &endpoint,
         r,
         tol,
     ) {
@@ -121,6 +238,10 @@ fn ctcompute(
         h1_expected_trial_duration = ct.h1_expected_trial_duration,
         h0_expected_sample_size = ct.h0_expected_sample_size,
         h1_expected_sample_size = ct.h1_expected_sample_size,
+        futility_boundaries = ct.futility_boundaries,
+        prob_futility_h0 = ct.prob_futility_h0,
+        prob_futility_h1 = ct.prob_futility_h1,
+        rmst_boundaries = ct.rmst_boundaries,
     )
 }
 
@@ -132,17 +253,23 @@ fn ctcompute(
 /// @param maybe_upper_spending_fcn (optional) spending function type for upper bound
 /// @param maybe_look_fractions (optional) information fractions at each trial look
 /// @param prop_treated proportion of patients who will be randomized to treatment arm
-/// @param lambda_event_trt hazard rate for event for treatment arm (assuming constant hazard)
-/// @param lambda_event_ctrl hazard rate for event for control arm (assuming constant hazard)
-/// @param maybe_lambda_dropout (optional) hazard rate for dropout (assuming constant hazard)
+/// @param lambda_event_trt_rates piecewise-constant hazard rates for event for treatment arm, one per interval
+/// @param lambda_event_trt_times change-point times at which `lambda_event_trt_rates` take effect (first entry must be 0)
+/// @param lambda_event_ctrl_rates piecewise-constant hazard rates for event for control arm, one per interval
+/// @param lambda_event_ctrl_times change-point times at which `lambda_event_ctrl_rates` take effect (first entry must be 0)
+/// @param maybe_lambda_dropout_rates (optional) piecewise-constant hazard rates for dropout, one per interval
+/// @param maybe_lambda_dropout_times (optional) change-point times at which `maybe_lambda_dropout_rates` take effect; must be specified together with `maybe_lambda_dropout_rates`
 /// @param enrollment_rates rates at which patients will be enrolled into the study
 /// @param enrollment_times times at which enrollment rates apply
 /// @param maybe_custom_alpha_spend when spending functions are specified as "custom", specifies the *cumulative* alpha to be spent at each look
+/// @param maybe_spending_param (optional) family parameter for the spending function: rho for "power" (rho ~= 1 is near-Pocock, rho ~= 3 is near-OBF), or gamma for "hsd"
+/// @param maybe_rmst_tau (optional) milestone time for a restricted mean survival time (RMST) endpoint; when omitted, the trial is sized for the logrank test
 /// @param tol desired precision of calculations. Results are not guaranteed to be within this distance of true values, but smaller tol values lead to more accurate calculations
 /// @param delta distance between points on grid of sample sizes to check; recommended to set to 1
 /// @param min_perc_change percent decrease in study duration per increment of sample size delta at which reductions are considered diminishing
 /// @export
 #[extendr]
+#[allow(clippy::too_many_arguments)]
 pub fn ss_range(
     alpha: f64,
     power: f64,
@@ -150,57 +277,53 @@ pub fn ss_range(
     maybe_upper_spending_fcn: Option<String>,
     maybe_look_fractions: Option<Vec<f64>>,
     prop_treated: f64,
-    lambda_event_trt: f64,
-    lambda_event_ctrl: f64,
-    maybe_lambda_dropout: Option<f64>,
+    lambda_event_trt_rates: Vec<f64>,
+    lambda_event_trt_times: Vec<f64>,
+    lambda_event_ctrl_rates: Vec<f64>,
+    lambda_event_ctrl_times: Vec<f64>,
+    maybe_lambda_dropout_rates: Option<Vec<f64>>,
+    maybe_lambda_dropout_times: Option<Vec<f64>>,
     enrollment_rates: Vec<f64>,
     enrollment_times: Vec<f64>,
     maybe_custom_alpha_spend: Option<Vec<f64>>,
+    maybe_spending_param: Option<f64>,
+    maybe_rmst_tau: Option<f64>,
     tol: f64,
     delta: f64,
     min_perc_change: f64,
 ) -> extendr_api::Result<Vec<usize>> {
-    let maybe_lower_spending_fcn = match (
+    let maybe_lower_spending_fcn = parse_spending_fcn(
         maybe_lower_spending_fcn.as_deref(),
         maybe_custom_alpha_spend.as_deref(),
-    ) {
-        (Some("LDOF"), _) => extendr_api::Result::Ok(Some(SpendingFcn::LDOF)),
-        (Some("custom"), Some(custom_alpha_spend)) => {
-            extendr_api::Result::Ok(Some(SpendingFcn::Custom {
-                cumulative_spend: custom_alpha_spend.into(),
-            }))
-        }
-        (Some("custom"), None) => extendr_api::throw_r_error(String::from(
-            "`maybe_custom_alpha_spend` must be specified when \
-                maybe_lower_spending_fcn = 'custom'",
-        )),
-        (None, _) => extendr_api::Result::Ok(None),
-        _ => extendr_api::Result::Err("invalid spending function".into()),
-    }?;
+        maybe_spending_param,
+    );
     let maybe_lower_spending_fcn_ref = maybe_lower_spending_fcn.as_ref();
 
-    let maybe_upper_spending_fcn = match (
+    let maybe_upper_spending_fcn = parse_spending_fcn(
         maybe_upper_spending_fcn.as_deref(),
         maybe_custom_alpha_spend.as_deref(),
-    ) {
-        (Some("LDOF"), _) => extendr_api::Result::Ok(Some(SpendingFcn::LDOF)),
-        (Some("custom"), Some(custom_alpha_spend)) => {
-            extendr_api::Result::Ok(Some(SpendingFcn::Custom {
-                cumulative_spend: custom_alpha_spend.into(),
-            }))
-        }
-        (Some("custom"), None) => extendr_api::throw_r_error(String::from(
-            "`maybe_custom_alpha_spend` must be specified when \
-                maybe_upper_spending_fcn = 'custom'",
-        )),
-        (None, _) => extendr_api::Result::Ok(None),
-        _ => extendr_api::Result::Err("invalid spending function".into()),
-    }?;
-
+        maybe_spending_param,
+    );
     let maybe_upper_spending_fcn_ref = maybe_upper_spending_fcn.as_ref();
 
     let maybe_look_fractions_ref = maybe_look_fractions.as_ref();
 
+    let lambda_event_trt = build_piecewise_hazard(
+        lambda_event_trt_times,
+        lambda_event_trt_rates,
+        "treatment arm event",
+    );
+    let lambda_event_ctrl = build_piecewise_hazard(
+        lambda_event_ctrl_times,
+        lambda_event_ctrl_rates,
+        "control arm event",
+    );
+    let maybe_lambda_dropout =
+        build_maybe_dropout_hazard(maybe_lambda_dropout_times, maybe_lambda_dropout_rates);
+    let maybe_lambda_dropout_ref = maybe_lambda_dropout.as_ref();
+
+    let endpoint = parse_endpoint(maybe_rmst_tau);
+
     let enrollment_rate = match EnrollmentRate::new(enrollment_times, enrollment_rates) {
         Ok(er) => er,
         Err(e) => {
@@ -216,10 +339,11 @@ pub fn ss_range(
         maybe_upper_spending_fcn_ref,
         maybe_look_fractions_ref,
         prop_treated,
-        lambda_event_trt,
-        lambda_event_ctrl,
-        maybe_lambda_dropout,
+        &lambda_event_trt,
+        &lambda_event_ctrl,
+        maybe_lambda_dropout_ref,
         &enrollment_rate,
+        &endpoint,
         tol,
         delta,
         min_perc_change,
@@ -234,6 +358,223 @@ pub fn ss_range(
     Ok(vec![ss_range_tup.0, ss_range_tup.1])
 }
 
+/// Computes conditional power at an interim analysis: the probability of
+/// eventually rejecting the null, conditional on the data observed so far
+/// and projected forward under an assumed effect.
+/// @param look index of the interim look at which conditional power is being computed (1-based)
+/// @param z_look observed z-statistic at the interim look
+/// @param info_look observed information at the interim look
+/// @param info_max planned maximum information at the final analysis
+/// @param alpha one-sided type-I error rate used for the final critical value
+/// @param maybe_theta (optional) effect size used to project forward; when omitted, the current estimate `z_look / sqrt(info_look)` is used
+/// @export
+#[extendr]
+fn conditional_power(
+    look: usize,
+    z_look: f64,
+    info_look: f64,
+    info_max: f64,
+    alpha: f64,
+    maybe_theta: Option<f64>,
+) -> List {
+    let cp = match compute_conditional_power(look, z_look, info_look, info_max, alpha, maybe_theta)
+    {
+        Ok(cp) => cp,
+        Err(e) => {
+            rprintln!("");
+            extendr_api::throw_r_error(&e.to_string())
+        }
+    };
+
+    list!(conditional_power = cp.conditional_power, theta = cp.theta,)
+}
+
+/// Performs Müller-Schäfer sample-size re-estimation at an interim look:
+/// inverts conditional power for the additional information needed to reach
+/// a target, and optionally preserves the overall type-I error by
+/// recomputing the final-stage critical value from the conditional error
+/// function.
+/// @param look index of the interim look (1-based)
+/// @param z_look observed z-statistic at the interim look
+/// @param info_look observed information at the interim look
+/// @param info_max planned maximum information at the final analysis
+/// @param alpha one-sided type-I error rate used for the final critical value
+/// @param target_power target conditional power for the remainder of the trial
+/// @param maybe_theta (optional) effect size used to invert for the additional information; when omitted, the current estimate `z_look / sqrt(info_look)` is used
+/// @param preserve_conditional_error whether to recompute the final-stage critical value from the conditional error function (Müller-Schäfer) so the overall type-I error is preserved after the design change
+/// @export
+#[extendr]
+fn reestimate_sample_size(
+    look: usize,
+    z_look: f64,
+    info_look: f64,
+    info_max: f64,
+    alpha: f64,
+    target_power: f64,
+    maybe_theta: Option<f64>,
+    preserve_conditional_error: bool,
+) -> List {
+    let reest = match compute_reestimate_sample_size(
+        look,
+        z_look,
+        info_look,
+        info_max,
+        alpha,
+        target_power,
+        maybe_theta,
+        preserve_conditional_error,
+    ) {
+        Ok(reest) => reest,
+        Err(e) => {
+            rprintln!("");
+            extendr_api::throw_r_error(&e.to_string())
+        }
+    };
+
+    list!(
+        additional_information = reest.additional_information,
+        new_info_max = reest.new_info_max,
+        revised_critical_value = reest.revised_critical_value,
+        conditional_error = reest.conditional_error,
+    )
+}
+
+/// Runs a Monte Carlo simulation of the trial design to cross-check the
+/// analytic approximations used by [`ctcompute`]: draws patient enrollment,
+/// event, and dropout times per replicate, applies the group-sequential
+/// boundaries at each look, and aggregates the results into empirical
+/// operating characteristics.
+/// @param n_patients the number of patients in the hypothetical trial
+/// @param alpha one-sided type-I error rate
+/// @param maybe_lower_spending_fcn (optional) spending function type for lower bound
+/// @param maybe_upper_spending_fcn (optional) spending function type for upper bound
+/// @param maybe_look_fractions (optional) information fractions at each trial look
+/// @param prop_treated proportion of patients who will be randomized to treatment arm
+/// @param lambda_event_trt_rates piecewise-constant hazard rates for event for treatment arm, one per interval
+/// @param lambda_event_trt_times change-point times at which `lambda_event_trt_rates` take effect (first entry must be 0)
+/// @param lambda_event_ctrl_rates piecewise-constant hazard rates for event for control arm, one per interval
+/// @param lambda_event_ctrl_times change-point times at which `lambda_event_ctrl_rates` take effect (first entry must be 0)
+/// @param maybe_lambda_dropout_rates (optional) piecewise-constant hazard rates for dropout, one per interval
+/// @param maybe_lambda_dropout_times (optional) change-point times at which `maybe_lambda_dropout_rates` take effect; must be specified together with `maybe_lambda_dropout_rates`
+/// @param enrollment_rates rates at which patients will be enrolled into the study
+/// @param enrollment_times times at which enrollment rates apply
+/// @param maybe_custom_alpha_spend when spending functions are specified as "custom", specifies the *cumulative* alpha to be spent at each look
+/// @param maybe_spending_param (optional) family parameter for the alpha spending function: rho for "power", or gamma for "hsd"
+/// @param maybe_beta_spending_fcn (optional) spending function type for the futility (type-II error) bound; when omitted, no futility monitoring is simulated
+/// @param maybe_custom_beta_spend when `maybe_beta_spending_fcn = "custom"`, specifies the *cumulative* beta to be spent at each look
+/// @param maybe_beta_spending_param (optional) family parameter for the beta spending function: rho for "power", or gamma for "hsd" (independent of `maybe_spending_param`, so the futility bound can use a different shape than the efficacy bound)
+/// @param binding whether the futility bound is treated as binding
+/// @param n_sims number of simulation replicates to draw under each hypothesis
+/// @param seed seed for the reproducible pseudo-random number generator used to draw replicates
+/// @export
+#[extendr]
+#[allow(clippy::too_many_arguments)]
+fn simulate_trial(
+    n_patients: usize,
+    alpha: f64,
+    maybe_lower_spending_fcn: Option<String>,
+    maybe_upper_spending_fcn: Option<String>,
+    maybe_look_fractions: Option<Vec<f64>>,
+    prop_treated: f64,
+    lambda_event_trt_rates: Vec<f64>,
+    lambda_event_trt_times: Vec<f64>,
+    lambda_event_ctrl_rates: Vec<f64>,
+    lambda_event_ctrl_times: Vec<f64>,
+    maybe_lambda_dropout_rates: Option<Vec<f64>>,
+    maybe_lambda_dropout_times: Option<Vec<f64>>,
+    enrollment_rates: Vec<f64>,
+    enrollment_times: Vec<f64>,
+    maybe_custom_alpha_spend: Option<Vec<f64>>,
+    maybe_spending_param: Option<f64>,
+    maybe_beta_spending_fcn: Option<String>,
+    maybe_custom_beta_spend: Option<Vec<f64>>,
+    maybe_beta_spending_param: Option<f64>,
+    binding: bool,
+    n_sims: usize,
+    seed: u64,
+) -> List {
+    let maybe_lower_spending_fcn = parse_spending_fcn(
+        maybe_lower_spending_fcn.as_deref(),
+        maybe_custom_alpha_spend.as_deref(),
+        maybe_spending_param,
+    );
+    let maybe_lower_spending_fcn_ref = maybe_lower_spending_fcn.as_ref();
+
+    let maybe_upper_spending_fcn = parse_spending_fcn(
+        maybe_upper_spending_fcn.as_deref(),
+        maybe_custom_alpha_spend.as_deref(),
+        maybe_spending_param,
+    );
+    let maybe_upper_spending_fcn_ref = maybe_upper_spending_fcn.as_ref();
+
+    let maybe_beta_spending_fcn = parse_spending_fcn(
+        maybe_beta_spending_fcn.as_deref(),
+        maybe_custom_beta_spend.as_deref(),
+        maybe_beta_spending_param,
+    );
+    let maybe_beta_spending_fcn_ref = maybe_beta_spending_fcn.as_ref();
+
+    let maybe_look_fractions_ref = maybe_look_fractions.as_ref();
+
+    let lambda_event_trt = build_piecewise_hazard(
+        lambda_event_trt_times,
+        lambda_event_trt_rates,
+        "treatment arm event",
+    );
+    let lambda_event_ctrl = build_piecewise_hazard(
+        lambda_event_ctrl_times,
+        lambda_event_ctrl_rates,
+        "control arm event",
+    );
+    let maybe_lambda_dropout =
+        build_maybe_dropout_hazard(maybe_lambda_dropout_times, maybe_lambda_dropout_rates);
+    let maybe_lambda_dropout_ref = maybe_lambda_dropout.as_ref();
+
+    let enrollment_rate = match EnrollmentRate::new(enrollment_times, enrollment_rates) {
+        Ok(er) => er,
+        Err(e) => {
+            rprintln!("");
+            extendr_api::throw_r_error(&e.to_string());
+        }
+    };
+
+    let sim = match run_trial_simulation(
+        n_patients,
+        alpha,
+        maybe_lower_spending_fcn_ref,
+        maybe_upper_spending_fcn_ref,
+        maybe_beta_spending_fcn_ref,
+        binding,
+        maybe_look_fractions_ref,
+        prop_treated,
+        &lambda_event_trt,
+        &lambda_event_ctrl,
+        maybe_lambda_dropout_ref,
+        &enrollment_rate,
+        n_sims,
+        seed,
+    ) {
+        Ok(sim) => sim,
+        Err(e) => {
+            rprintln!("");
+            extendr_api::throw_r_error(&e.to_string())
+        }
+    };
+
+    list!(
+        empirical_power = sim.empirical_power,
+        empirical_power_se = sim.empirical_power_se,
+        empirical_type1_error = sim.empirical_type1_error,
+        empirical_type1_error_se = sim.empirical_type1_error_se,
+        expected_n_events = sim.expected_n_events,
+        expected_n_events_se = sim.expected_n_events_se,
+        expected_accrual_duration = sim.expected_accrual_duration,
+        expected_accrual_duration_se = sim.expected_accrual_duration_se,
+        expected_trial_duration = sim.expected_trial_duration,
+        expected_trial_duration_se = sim.expected_trial_duration_se,
+    )
+}
+
 // Macro to generate exports.
 // This ensures exported functions are registered with R.
 // See corresponding C code in `entrypoint.c`.
@@ -241,4 +582,7 @@ extendr_module! {
     mod ctcomputeR;
     fn ctcompute;
     fn ss_range;
+    fn conditional_power;
+    fn reestimate_sample_size;
+    fn simulate_trial;
 }